@@ -52,35 +52,226 @@ fn get_favorites_path() -> PathBuf {
     get_config_dir().join("github_favorites.json")
 }
 
+fn get_accounts_path() -> PathBuf {
+    get_config_dir().join("github_accounts.json")
+}
+
+const DEFAULT_ACCOUNT_NAME: &str = "default";
+
+/// 토큰은 OS 키체인에 `git-manager` 서비스, `github/<계정 이름>` 항목으로 저장
+const KEYRING_SERVICE: &str = "git-manager";
+
+fn keyring_entry(name: &str) -> Result<keyring::Entry, String> {
+    keyring::Entry::new(KEYRING_SERVICE, &format!("github/{}", name))
+        .map_err(|e| format!("키체인 접근 실패: {}", e))
+}
+
+fn store_token(name: &str, token: &str) -> Result<(), String> {
+    keyring_entry(name)?
+        .set_password(token)
+        .map_err(|e| format!("키체인 저장 실패: {}", e))
+}
+
+fn load_token(name: &str) -> Result<Option<String>, String> {
+    match keyring_entry(name)?.get_password() {
+        Ok(token) => Ok(Some(token)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(e) => Err(format!("키체인 읽기 실패: {}", e)),
+    }
+}
+
+fn delete_token(name: &str) -> Result<(), String> {
+    match keyring_entry(name)?.delete_credential() {
+        Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+        Err(e) => Err(format!("키체인 삭제 실패: {}", e)),
+    }
+}
+
+/// 계정 파일에는 이름과 로그인만 저장. token은 예전 형식 파일을 읽어 키체인으로 옮길 때만 사용
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct StoredGitHubAccount {
+    name: String,
+    login: Option<String>,
+    #[serde(default, skip_serializing)]
+    token: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+struct GitHubAccountStore {
+    active: Option<String>,
+    accounts: Vec<StoredGitHubAccount>,
+}
+
+impl GitHubAccountStore {
+    fn active_account(&self) -> Option<&StoredGitHubAccount> {
+        let active = self.active.as_ref()?;
+        self.accounts.iter().find(|a| &a.name == active)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GitHubAccount {
+    pub name: String,
+    pub login: Option<String>,
+    pub active: bool,
+}
+
+/// 계정 목록 읽기. 예전 단일 토큰 파일이나 토큰이 평문으로 들어 있는 계정 파일은 키체인으로 옮긴 뒤 정리
+fn load_accounts() -> Result<GitHubAccountStore, String> {
+    let path = get_accounts_path();
+    if path.exists() {
+        let content = fs::read_to_string(&path).map_err(|e| format!("계정 읽기 실패: {}", e))?;
+        let mut store: GitHubAccountStore =
+            serde_json::from_str(&content).map_err(|e| format!("계정 파싱 실패: {}", e))?;
+
+        let mut migrated = false;
+        for account in &mut store.accounts {
+            if let Some(token) = account.token.take() {
+                store_token(&account.name, token.trim())?;
+                migrated = true;
+            }
+        }
+        if migrated {
+            save_accounts(&store)?;
+        }
+        return Ok(store);
+    }
+
+    let mut store = GitHubAccountStore::default();
+    let legacy_path = get_token_path();
+    if let Ok(token) = fs::read_to_string(&legacy_path) {
+        if !token.trim().is_empty() {
+            store_token(DEFAULT_ACCOUNT_NAME, token.trim())?;
+            store.accounts.push(StoredGitHubAccount {
+                name: DEFAULT_ACCOUNT_NAME.to_string(),
+                login: None,
+                token: None,
+            });
+            store.active = Some(DEFAULT_ACCOUNT_NAME.to_string());
+            save_accounts(&store)?;
+        }
+        fs::remove_file(&legacy_path).map_err(|e| format!("예전 토큰 파일 삭제 실패: {}", e))?;
+    }
+    Ok(store)
+}
+
+fn save_accounts(store: &GitHubAccountStore) -> Result<(), String> {
+    let content = serde_json::to_string_pretty(store).map_err(|e| format!("계정 직렬화 실패: {}", e))?;
+    fs::write(get_accounts_path(), content).map_err(|e| format!("계정 저장 실패: {}", e))
+}
+
+/// 활성 계정의 토큰을 저장 (활성 계정이 없으면 "default" 계정 생성)
 #[tauri::command]
 pub fn save_github_token(token: String) -> Result<(), String> {
-    let path = get_token_path();
-    fs::write(&path, &token).map_err(|e| format!("토큰 저장 실패: {}", e))?;
-    Ok(())
+    let mut store = load_accounts()?;
+    let name = store
+        .active
+        .clone()
+        .unwrap_or_else(|| DEFAULT_ACCOUNT_NAME.to_string());
+
+    store_token(&name, &token)?;
+    match store.accounts.iter_mut().find(|a| a.name == name) {
+        Some(account) => account.login = None,
+        None => store.accounts.push(StoredGitHubAccount {
+            name: name.clone(),
+            login: None,
+            token: None,
+        }),
+    }
+    store.active = Some(name);
+    save_accounts(&store)
 }
 
+/// 활성 계정의 토큰
 #[tauri::command]
 pub fn get_github_token() -> Result<Option<String>, String> {
-    let path = get_token_path();
-    if path.exists() {
-        let token = fs::read_to_string(&path).map_err(|e| format!("토큰 읽기 실패: {}", e))?;
-        if token.trim().is_empty() {
-            Ok(None)
-        } else {
-            Ok(Some(token.trim().to_string()))
-        }
-    } else {
-        Ok(None)
-    }
+    let store = load_accounts()?;
+    let Some(account) = store.active_account() else {
+        return Ok(None);
+    };
+    Ok(load_token(&account.name)?
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty()))
 }
 
+/// 활성 계정 삭제 (로그아웃)
 #[tauri::command]
 pub fn delete_github_token() -> Result<(), String> {
-    let path = get_token_path();
-    if path.exists() {
-        fs::remove_file(&path).map_err(|e| format!("토큰 삭제 실패: {}", e))?;
+    let store = load_accounts()?;
+    match store.active {
+        Some(name) => delete_github_account(name),
+        None => Ok(()),
     }
-    Ok(())
+}
+
+/// 이름을 붙여 GitHub 계정 추가 (같은 이름이 있으면 토큰 교체). 토큰으로 로그인 이름을 확인함
+#[tauri::command]
+pub async fn add_github_account(name: String, token: String) -> Result<GitHubAccount, String> {
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err("계정 이름을 입력하세요".to_string());
+    }
+
+    let user = fetch_github_user(token.clone()).await?;
+
+    let mut store = load_accounts()?;
+    store_token(&name, &token)?;
+    store.accounts.retain(|a| a.name != name);
+    store.accounts.push(StoredGitHubAccount {
+        name: name.clone(),
+        login: Some(user.login.clone()),
+        token: None,
+    });
+    if store.active_account().is_none() {
+        store.active = Some(name.clone());
+    }
+    save_accounts(&store)?;
+
+    Ok(GitHubAccount {
+        active: store.active.as_deref() == Some(name.as_str()),
+        name,
+        login: Some(user.login),
+    })
+}
+
+#[tauri::command]
+pub fn list_github_accounts() -> Result<Vec<GitHubAccount>, String> {
+    let store = load_accounts()?;
+    Ok(store
+        .accounts
+        .iter()
+        .map(|a| GitHubAccount {
+            name: a.name.clone(),
+            login: a.login.clone(),
+            active: store.active.as_ref() == Some(&a.name),
+        })
+        .collect())
+}
+
+#[tauri::command]
+pub fn set_active_github_account(name: String) -> Result<(), String> {
+    let mut store = load_accounts()?;
+    if !store.accounts.iter().any(|a| a.name == name) {
+        return Err(format!("계정을 찾을 수 없습니다: {}", name));
+    }
+    store.active = Some(name);
+    save_accounts(&store)
+}
+
+/// 계정 삭제 (키체인의 토큰 포함). 활성 계정이었으면 남은 첫 계정이 활성화됨
+#[tauri::command]
+pub fn delete_github_account(name: String) -> Result<(), String> {
+    let mut store = load_accounts()?;
+    let before = store.accounts.len();
+    store.accounts.retain(|a| a.name != name);
+    if store.accounts.len() == before {
+        return Err(format!("계정을 찾을 수 없습니다: {}", name));
+    }
+    delete_token(&name)?;
+    if store.active.as_ref() == Some(&name) {
+        store.active = store.accounts.first().map(|a| a.name.clone());
+    }
+    save_accounts(&store)
 }
 
 #[tauri::command]
@@ -202,22 +393,42 @@ struct CreateRepoRequest {
     description: Option<String>,
     private: bool,
     auto_init: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    gitignore_template: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    license_template: Option<String>,
 }
 
+/// GitHub 저장소 생성
+///
+/// `auto_init`이 false면 빈 저장소를 만들어 로컬 저장소를 push하는 용도이고,
+/// true면 초기 커밋(README, .gitignore, LICENSE)이 생성되므로 push 대신 반환된 `clone_url`로 clone해야 함.
+/// `gitignore_template`/`license_template`은 `auto_init`이 true일 때만 적용됨.
 #[tauri::command]
 pub async fn create_github_repo(
     token: String,
     name: String,
     description: Option<String>,
     private: bool,
+    auto_init: bool,
+    gitignore_template: Option<String>,
+    license_template: Option<String>,
 ) -> Result<GitHubRepo, String> {
     let client = reqwest::Client::new();
 
+    let (gitignore_template, license_template) = if auto_init {
+        (gitignore_template, license_template)
+    } else {
+        (None, None)
+    };
+
     let request_body = CreateRepoRequest {
         name,
         description,
         private,
-        auto_init: false, // 로컬 저장소를 push할 것이므로 초기화하지 않음
+        auto_init,
+        gitignore_template,
+        license_template,
     };
 
     let response = client
@@ -243,3 +454,586 @@ pub async fn create_github_repo(
 
     Ok(repo)
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CommitStatusItem {
+    pub context: String,
+    pub state: String,
+    pub target_url: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CombinedStatus {
+    pub state: String,
+    pub total_count: i32,
+    pub statuses: Vec<CommitStatusItem>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CheckRun {
+    name: String,
+    status: String,
+    conclusion: Option<String>,
+    html_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CheckRunsResponse {
+    check_runs: Vec<CheckRun>,
+}
+
+/// check run의 status/conclusion을 commit status의 state(success/failure/pending)로 변환
+fn check_run_state(run: &CheckRun) -> String {
+    if run.status != "completed" {
+        return "pending".to_string();
+    }
+    match run.conclusion.as_deref() {
+        Some("success") | Some("neutral") | Some("skipped") => "success",
+        _ => "failure",
+    }
+    .to_string()
+}
+
+/// 커밋(브랜치/태그/SHA)의 CI 상태. commit status와 check run(GitHub Actions 등)을 합쳐서 반환
+#[tauri::command]
+pub async fn fetch_github_commit_status(
+    token: String,
+    owner: String,
+    repo: String,
+    git_ref: String,
+) -> Result<CombinedStatus, String> {
+    let client = reqwest::Client::new();
+    let response = client
+        .get(format!("https://api.github.com/repos/{}/{}/commits/{}/status", owner, repo, git_ref))
+        .header("Authorization", format!("Bearer {}", token))
+        .header("User-Agent", "git-manager-tauri")
+        .header("Accept", "application/vnd.github+json")
+        .send()
+        .await
+        .map_err(|e| format!("API 요청 실패: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("GitHub API 오류: {}", response.status()));
+    }
+
+    let mut combined: CombinedStatus = response
+        .json()
+        .await
+        .map_err(|e| format!("응답 파싱 실패: {}", e))?;
+
+    // check run은 토큰 권한에 따라 조회가 안 될 수 있으므로 실패해도 commit status만 반환
+    let check_runs = client
+        .get(format!("https://api.github.com/repos/{}/{}/commits/{}/check-runs", owner, repo, git_ref))
+        .query(&[("per_page", "100")])
+        .header("Authorization", format!("Bearer {}", token))
+        .header("User-Agent", "git-manager-tauri")
+        .header("Accept", "application/vnd.github+json")
+        .send()
+        .await
+        .ok()
+        .filter(|r| r.status().is_success());
+
+    if let Some(response) = check_runs {
+        if let Ok(runs) = response.json::<CheckRunsResponse>().await {
+            for run in runs.check_runs {
+                combined.statuses.push(CommitStatusItem {
+                    state: check_run_state(&run),
+                    context: run.name,
+                    target_url: run.html_url,
+                });
+            }
+        }
+    }
+
+    // 상태가 하나도 없으면 GitHub가 돌려준 state("pending")를 그대로 사용
+    combined.total_count = combined.statuses.len() as i32;
+    if !combined.statuses.is_empty() {
+        let states: Vec<&str> = combined.statuses.iter().map(|s| s.state.as_str()).collect();
+        combined.state = if states.iter().any(|s| *s == "failure" || *s == "error") {
+            "failure"
+        } else if states.contains(&"pending") {
+            "pending"
+        } else {
+            "success"
+        }
+        .to_string();
+    }
+
+    Ok(combined)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PullRequestBranch {
+    /// "owner:branch" 형식
+    pub label: String,
+    #[serde(rename = "ref")]
+    pub ref_name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PullRequest {
+    pub number: i64,
+    pub title: String,
+    pub html_url: String,
+    pub state: String,
+    #[serde(default)]
+    pub draft: bool,
+    pub head: PullRequestBranch,
+    pub base: PullRequestBranch,
+}
+
+/// 브랜치에서 열린 PR 조회. `head_branch`가 "owner:branch" 형식이면(fork) 그대로 사용하고, 아니면 저장소 owner를 붙임
+#[tauri::command]
+pub async fn find_pull_for_branch(
+    token: String,
+    owner: String,
+    repo: String,
+    head_branch: String,
+) -> Result<Option<PullRequest>, String> {
+    let head = if head_branch.contains(':') {
+        head_branch
+    } else {
+        format!("{}:{}", owner, head_branch)
+    };
+
+    let client = reqwest::Client::new();
+    let response = client
+        .get(format!("https://api.github.com/repos/{}/{}/pulls", owner, repo))
+        .query(&[("head", head.as_str()), ("state", "open")])
+        .header("Authorization", format!("Bearer {}", token))
+        .header("User-Agent", "git-manager-tauri")
+        .header("Accept", "application/vnd.github+json")
+        .send()
+        .await
+        .map_err(|e| format!("API 요청 실패: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("GitHub API 오류: {}", response.status()));
+    }
+
+    let pulls: Vec<PullRequest> = response
+        .json()
+        .await
+        .map_err(|e| format!("응답 파싱 실패: {}", e))?;
+
+    Ok(pulls.into_iter().next())
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LanguageStat {
+    pub name: String,
+    pub bytes: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RepoMetadata {
+    /// 바이트 수 내림차순
+    pub languages: Vec<LanguageStat>,
+    pub topics: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TopicsResponse {
+    names: Vec<String>,
+}
+
+/// 저장소의 언어 구성과 토픽
+#[tauri::command]
+pub async fn fetch_github_repo_metadata(
+    token: String,
+    owner: String,
+    repo: String,
+) -> Result<RepoMetadata, String> {
+    let client = reqwest::Client::new();
+    let response = client
+        .get(format!("https://api.github.com/repos/{}/{}/languages", owner, repo))
+        .header("Authorization", format!("Bearer {}", token))
+        .header("User-Agent", "git-manager-tauri")
+        .header("Accept", "application/vnd.github+json")
+        .send()
+        .await
+        .map_err(|e| format!("API 요청 실패: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("GitHub API 오류: {}", response.status()));
+    }
+
+    let language_map: std::collections::HashMap<String, u64> = response
+        .json()
+        .await
+        .map_err(|e| format!("응답 파싱 실패: {}", e))?;
+    let mut languages: Vec<LanguageStat> = language_map
+        .into_iter()
+        .map(|(name, bytes)| LanguageStat { name, bytes })
+        .collect();
+    languages.sort_by(|a, b| b.bytes.cmp(&a.bytes).then_with(|| a.name.cmp(&b.name)));
+
+    // topics는 preview accept 헤더가 필요함
+    let response = client
+        .get(format!("https://api.github.com/repos/{}/{}/topics", owner, repo))
+        .header("Authorization", format!("Bearer {}", token))
+        .header("User-Agent", "git-manager-tauri")
+        .header("Accept", "application/vnd.github.mercy-preview+json")
+        .send()
+        .await
+        .map_err(|e| format!("API 요청 실패: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("GitHub API 오류: {}", response.status()));
+    }
+
+    let topics: TopicsResponse = response
+        .json()
+        .await
+        .map_err(|e| format!("응답 파싱 실패: {}", e))?;
+
+    Ok(RepoMetadata {
+        languages,
+        topics: topics.names,
+    })
+}
+
+/// 저장소 README 원문 (markdown). 기본 브랜치 기준
+#[tauri::command]
+pub async fn fetch_github_readme(token: String, owner: String, repo: String) -> Result<String, String> {
+    let client = reqwest::Client::new();
+    let response = client
+        .get(format!("https://api.github.com/repos/{}/{}/readme", owner, repo))
+        .header("Authorization", format!("Bearer {}", token))
+        .header("User-Agent", "git-manager-tauri")
+        .header("Accept", "application/vnd.github.raw")
+        .send()
+        .await
+        .map_err(|e| format!("API 요청 실패: {}", e))?;
+
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Err("README가 없습니다".to_string());
+    }
+    if !response.status().is_success() {
+        return Err(format!("GitHub API 오류: {}", response.status()));
+    }
+
+    response
+        .text()
+        .await
+        .map_err(|e| format!("응답 읽기 실패: {}", e))
+}
+
+// ============ .gitignore 템플릿 ============
+
+fn get_gitignore_cache_dir() -> PathBuf {
+    let dir = get_config_dir().join("gitignore_templates");
+    fs::create_dir_all(&dir).ok();
+    dir
+}
+
+#[derive(Debug, Deserialize)]
+struct GitignoreTemplate {
+    source: String,
+}
+
+/// 공개 API라 토큰 없이 호출
+async fn fetch_gitignore_api<T: serde::de::DeserializeOwned>(url: &str) -> Result<T, String> {
+    let client = reqwest::Client::new();
+    let response = client
+        .get(url)
+        .header("User-Agent", "git-manager-tauri")
+        .header("Accept", "application/vnd.github+json")
+        .send()
+        .await
+        .map_err(|e| format!("API 요청 실패: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("GitHub API 오류: {}", response.status()));
+    }
+
+    response
+        .json()
+        .await
+        .map_err(|e| format!("응답 파싱 실패: {}", e))
+}
+
+/// GitHub .gitignore 템플릿 이름 목록 (한 번 받으면 로컬에 캐시)
+#[tauri::command]
+pub async fn list_gitignore_templates() -> Result<Vec<String>, String> {
+    let cache = get_gitignore_cache_dir().join("_list.json");
+    if let Ok(content) = fs::read_to_string(&cache) {
+        if let Ok(names) = serde_json::from_str(&content) {
+            return Ok(names);
+        }
+    }
+
+    let names: Vec<String> = fetch_gitignore_api("https://api.github.com/gitignore/templates").await?;
+    if let Ok(content) = serde_json::to_string(&names) {
+        let _ = fs::write(&cache, content);
+    }
+    Ok(names)
+}
+
+/// 템플릿 내용 (캐시 우선)
+async fn load_gitignore_template(name: &str) -> Result<String, String> {
+    // 캐시 파일 이름으로 쓰이므로 경로 문자 차단 (C++, Objective-C 등은 허용)
+    let valid = !name.is_empty()
+        && !name.starts_with('.')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '_' | '.'));
+    if !valid {
+        return Err(format!("잘못된 템플릿 이름입니다: {}", name));
+    }
+
+    let cache = get_gitignore_cache_dir().join(format!("{}.gitignore", name));
+    if let Ok(content) = fs::read_to_string(&cache) {
+        return Ok(content);
+    }
+
+    let template: GitignoreTemplate =
+        fetch_gitignore_api(&format!("https://api.github.com/gitignore/templates/{}", name)).await?;
+    let _ = fs::write(&cache, &template.source);
+    Ok(template.source)
+}
+
+/// 템플릿을 저장소의 .gitignore 끝에 추가. 이미 있는 규칙은 건너뜀
+#[tauri::command]
+pub async fn apply_gitignore_template(path: String, template: String) -> Result<(), String> {
+    let source = load_gitignore_template(&template).await?;
+
+    let gitignore = std::path::Path::new(&path).join(".gitignore");
+    let existing = fs::read_to_string(&gitignore).unwrap_or_default();
+    let present: std::collections::HashSet<&str> = existing.lines().map(|l| l.trim()).collect();
+
+    let new_lines: Vec<&str> = source
+        .lines()
+        .map(|l| l.trim_end())
+        .filter(|l| {
+            let trimmed = l.trim();
+            trimmed.is_empty() || trimmed.starts_with('#') || !present.contains(trimmed)
+        })
+        .collect();
+
+    // 주석/빈 줄만 남으면 추가할 규칙이 없는 것
+    if !new_lines.iter().any(|l| !l.trim().is_empty() && !l.trim().starts_with('#')) {
+        return Ok(());
+    }
+
+    let mut content = existing.clone();
+    if !content.is_empty() && !content.ends_with('\n') {
+        content.push('\n');
+    }
+    if !content.is_empty() {
+        content.push('\n');
+    }
+    content.push_str(&format!("# ===== {} =====\n", template));
+    content.push_str(new_lines.join("\n").trim());
+    content.push('\n');
+
+    fs::write(&gitignore, content).map_err(|e| format!(".gitignore 저장 실패: {}", e))
+}
+
+// ============ Gist ============
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Gist {
+    pub id: String,
+    pub description: Option<String>,
+    /// 파일 이름 목록
+    pub files: Vec<String>,
+    pub public: bool,
+    pub html_url: String,
+    pub git_pull_url: String,
+    pub updated_at: String,
+}
+
+#[derive(Deserialize)]
+struct GistResponse {
+    id: String,
+    description: Option<String>,
+    files: std::collections::BTreeMap<String, serde_json::Value>,
+    public: bool,
+    html_url: String,
+    git_pull_url: String,
+    updated_at: String,
+}
+
+/// Link 헤더에서 rel="next" URL 추출
+fn next_page_url(headers: &reqwest::header::HeaderMap) -> Option<String> {
+    let link = headers.get("link")?.to_str().ok()?;
+    link.split(',').find_map(|part| {
+        let (url, rel) = part.split_once(';')?;
+        if rel.trim() == "rel=\"next\"" {
+            Some(url.trim().trim_start_matches('<').trim_end_matches('>').to_string())
+        } else {
+            None
+        }
+    })
+}
+
+/// 인증된 사용자의 gist 목록 (Link 헤더를 따라 최대 10페이지)
+#[tauri::command]
+pub async fn fetch_github_gists(token: String) -> Result<Vec<Gist>, String> {
+    let client = reqwest::Client::new();
+    let mut gists = Vec::new();
+    let mut url = Some("https://api.github.com/gists?per_page=100".to_string());
+    let mut pages = 0;
+
+    while let Some(current) = url.take() {
+        let response = client
+            .get(&current)
+            .header("Authorization", format!("Bearer {}", token))
+            .header("User-Agent", "git-manager-tauri")
+            .header("Accept", "application/vnd.github+json")
+            .send()
+            .await
+            .map_err(|e| format!("API 요청 실패: {}", e))?;
+
+        if !response.status().is_success() {
+            return Err(format!("GitHub API 오류: {}", response.status()));
+        }
+
+        let next = next_page_url(response.headers());
+        let page: Vec<GistResponse> = response
+            .json()
+            .await
+            .map_err(|e| format!("응답 파싱 실패: {}", e))?;
+
+        gists.extend(page.into_iter().map(|g| Gist {
+            id: g.id,
+            description: g.description.filter(|d| !d.is_empty()),
+            files: g.files.into_keys().collect(),
+            public: g.public,
+            html_url: g.html_url,
+            git_pull_url: g.git_pull_url,
+            updated_at: g.updated_at,
+        }));
+
+        pages += 1;
+        if pages < 10 {
+            url = next;
+        }
+    }
+
+    Ok(gists)
+}
+
+/// gist를 로컬에 clone (gist도 일반 git 저장소)
+#[tauri::command]
+pub fn clone_gist(git_pull_url: String, path: String) -> Result<(), String> {
+    if !git_pull_url.starts_with("https://gist.github.com/") {
+        return Err(format!("gist 주소가 아닙니다: {}", git_pull_url));
+    }
+    crate::git::clone_repo(&git_pull_url, &path)
+}
+
+// ============ Actions ============
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WorkflowRun {
+    pub id: i64,
+    pub workflow_id: i64,
+    pub name: Option<String>,
+    /// "queued" / "in_progress" / "completed" 등
+    pub status: Option<String>,
+    /// 완료 전에는 None
+    pub conclusion: Option<String>,
+    pub head_sha: String,
+    pub html_url: String,
+    pub created_at: String,
+}
+
+#[derive(Deserialize)]
+struct WorkflowRunsResponse {
+    workflow_runs: Vec<WorkflowRun>,
+}
+
+#[derive(Deserialize)]
+struct WorkflowSummary {
+    id: i64,
+}
+
+#[derive(Deserialize)]
+struct WorkflowsResponse {
+    workflows: Vec<WorkflowSummary>,
+}
+
+/// Actions API GET. Actions가 꺼진 저장소(404)는 None
+async fn get_actions_page<T: serde::de::DeserializeOwned>(
+    client: &reqwest::Client,
+    url: &str,
+    token: &str,
+) -> Result<Option<(T, Option<String>)>, String> {
+    let response = client
+        .get(url)
+        .header("Authorization", format!("Bearer {}", token))
+        .header("User-Agent", "git-manager-tauri")
+        .header("Accept", "application/vnd.github+json")
+        .send()
+        .await
+        .map_err(|e| format!("API 요청 실패: {}", e))?;
+
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    if !response.status().is_success() {
+        return Err(format!("GitHub API 오류: {}", response.status()));
+    }
+
+    let next = next_page_url(response.headers());
+    let body = response
+        .json()
+        .await
+        .map_err(|e| format!("응답 파싱 실패: {}", e))?;
+    Ok(Some((body, next)))
+}
+
+/// 워크플로별 최신 GitHub Actions 실행 (최신순). Actions가 꺼진 저장소는 빈 목록
+///
+/// 실행 목록을 Link 헤더로 따라가다가 모든 워크플로의 실행을 찾았거나 마지막 페이지면 멈춤
+#[tauri::command]
+pub async fn fetch_github_workflow_runs(
+    token: String,
+    owner: String,
+    repo: String,
+    branch: Option<String>,
+) -> Result<Vec<WorkflowRun>, String> {
+    let client = reqwest::Client::new();
+    let base = format!("https://api.github.com/repos/{}/{}/actions", owner, repo);
+
+    // 찾아야 할 워크플로 목록
+    let mut workflow_ids = std::collections::HashSet::new();
+    let mut url = Some(format!("{}/workflows?per_page=100", base));
+    while let Some(current) = url.take() {
+        let Some((page, next)) = get_actions_page::<WorkflowsResponse>(&client, &current, &token).await? else {
+            return Ok(Vec::new());
+        };
+        workflow_ids.extend(page.workflows.into_iter().map(|w| w.id));
+        url = next;
+    }
+    if workflow_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut first = reqwest::Url::parse(&format!("{}/runs", base)).map_err(|e| e.to_string())?;
+    first.query_pairs_mut().append_pair("per_page", "100");
+    if let Some(branch) = &branch {
+        first.query_pairs_mut().append_pair("branch", branch);
+    }
+
+    // 응답이 최신순이므로 워크플로마다 처음 나온 실행만 남김
+    let mut runs: Vec<WorkflowRun> = Vec::new();
+    let mut url = Some(first.to_string());
+    while let Some(current) = url.take() {
+        let Some((page, next)) = get_actions_page::<WorkflowRunsResponse>(&client, &current, &token).await? else {
+            return Ok(Vec::new());
+        };
+        for run in page.workflow_runs {
+            if workflow_ids.remove(&run.workflow_id) {
+                runs.push(run);
+            }
+        }
+        if !workflow_ids.is_empty() {
+            url = next;
+        }
+    }
+
+    Ok(runs)
+}
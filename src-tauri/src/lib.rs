@@ -16,38 +16,90 @@ pub fn run() {
         .plugin(tauri_plugin_fs::init())
         .invoke_handler(tauri::generate_handler![
             get_repo_info,
+            get_repo_commit_state,
             get_status,
+            get_status_if_changed,
+            is_lfs_tracked,
+            is_working_tree_clean,
+            list_index_entries,
+            list_tree_with_last_commit,
             stage_file,
             unstage_file,
             stage_all,
+            intent_to_add,
             commit,
+            commit_full,
+            validate_commit_message,
+            quick_commit,
+            commit_partial,
             push,
             push_to_remote,
+            push_new_branch,
+            push_dry_run,
             pull,
             fetch_remote,
             get_branches,
+            get_available_branches,
             checkout_branch,
+            get_recent_branches,
             get_log,
             get_graph_log,
+            get_commit_parents,
+            get_commits_batch,
+            export_graph_dot,
+            get_unpushed_commits,
+            get_unpulled_commits,
+            resolve_ref,
+            rev_parse,
+            describe_commit,
+            get_commit_notes,
+            set_commit_notes,
             get_diff,
             get_staged_diff,
             get_commit_diff,
+            get_commit_stats,
+            get_worktree_diff_stat,
+            compare_commits,
+            compare_commits_patch,
+            get_divergence,
+            commit_contained_in,
+            get_blame,
+            get_blame_summary,
             discard_changes,
             checkout_commit,
             create_branch_at,
+            check_branch_name,
             reset_to_commit,
+            undo_last,
             create_tag,
+            get_tags,
+            commits_between_tags,
             cherry_pick,
+            cherry_pick_with_mainline,
             revert_commit,
             stash_save,
+            stash_unstaged,
             stash_pop,
             stash_list,
+            stash_entries,
             stash_drop,
             stash_apply,
+            stash_branch,
+            move_changes_to_branch,
+            can_pop_stash,
             delete_branch,
+            can_delete_branch,
+            is_on_default_branch,
+            branch_vs_default,
             rename_branch,
+            migrate_default_branch,
             merge_branch,
+            preview_merge_conflicts,
             rebase_onto,
+            reword_commit,
+            amend_commit_meta,
+            get_sequencer_status,
+            get_rebase_conflict_context,
             get_remote_status,
             // 원격 저장소 관리
             get_remotes,
@@ -55,32 +107,93 @@ pub fn run() {
             remove_remote,
             set_remote_url,
             rename_remote,
+            get_remote_refspecs,
+            set_remote_refspec,
+            parse_github_remote,
+            check_remote_access,
+            github_permalink,
             get_remote_branches,
             checkout_remote_branch,
+            checkout_pull_request,
             delete_remote_branch,
             prune_remote,
+            find_stale_remote_branches,
             fetch_from_remote,
+            // 저장소 상태 점검
+            get_repo_stats,
+            run_gc,
+            check_git_locks,
+            clear_git_lock,
+            find_dangling_commits,
+            // 로컬 변경 숨기기
+            set_assume_unchanged,
+            set_skip_worktree,
+            list_assume_unchanged,
+            // 훅
+            list_hooks,
+            // 충돌 해결
+            get_conflict_versions,
+            write_conflict_resolution,
+            // 설정
+            get_merged_config,
+            get_line_ending_config,
+            set_autocrlf,
+            get_conflict_style,
+            set_conflict_style,
+            // 읽기 전용 git 명령
+            run_git_readonly,
+            // 스냅샷 내보내기
+            export_archive,
+            // sparse checkout
+            get_sparse_checkout,
+            set_sparse_checkout,
+            disable_sparse_checkout,
+            // 서브모듈
+            get_submodule_status,
             // 파일 감시
             watch_repo,
             unwatch_repo,
+            set_watch_remote_refs,
             unwatch_all,
+            start_auto_fetch,
+            stop_auto_fetch,
             // AI 커밋 메시지 생성
             get_ai_config,
             save_ai_config,
+            get_effective_ai_config,
+            preview_ai_prompt,
             generate_commit_message,
+            cancel_ai_generation,
+            list_ollama_models,
+            list_openai_models,
+            list_anthropic_models,
             // 저장소 초기화 및 복제
             init_repo,
+            init_and_first_commit,
             clone_repo,
             // GitHub API
             save_github_token,
             get_github_token,
             delete_github_token,
+            add_github_account,
+            list_github_accounts,
+            set_active_github_account,
+            delete_github_account,
             fetch_github_user,
             fetch_github_repos,
             get_github_favorites,
             add_github_favorite,
             remove_github_favorite,
             create_github_repo,
+            fetch_github_commit_status,
+            find_pull_for_branch,
+            fetch_github_repo_metadata,
+            fetch_github_readme,
+            list_gitignore_templates,
+            apply_gitignore_template,
+            fetch_github_gists,
+            clone_gist,
+            fetch_github_workflow_runs,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
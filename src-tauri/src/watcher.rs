@@ -1,14 +1,40 @@
 use notify::{Config, RecommendedWatcher, RecursiveMode, Watcher, Event};
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 use tauri::{AppHandle, Emitter};
+use tokio_util::sync::CancellationToken;
 
 type WatcherMap = Arc<Mutex<HashMap<String, RecommendedWatcher>>>;
 
 lazy_static::lazy_static! {
     static ref WATCHERS: WatcherMap = Arc::new(Mutex::new(HashMap::new()));
+    static ref REMOTE_REF_FLAGS: Mutex<HashMap<String, Arc<AtomicBool>>> = Mutex::new(HashMap::new());
+    static ref STATUS_GENERATIONS: Mutex<HashMap<String, u64>> = Mutex::new(HashMap::new());
+    static ref EMIT_STATUS_FLAGS: Mutex<HashMap<String, Arc<AtomicBool>>> = Mutex::new(HashMap::new());
+    static ref AUTO_FETCH_TASKS: Mutex<HashMap<String, CancellationToken>> = Mutex::new(HashMap::new());
+}
+
+/// 감시 중인 저장소의 status 세대 번호. 감시 중이 아니면 None
+pub fn status_generation(path: &str) -> Option<u64> {
+    STATUS_GENERATIONS.lock().ok()?.get(path).copied()
+}
+
+fn bump_status_generation(path: &str) {
+    if let Ok(mut generations) = STATUS_GENERATIONS.lock() {
+        if let Some(generation) = generations.get_mut(path) {
+            *generation += 1;
+        }
+    }
+}
+
+/// .git 내부 변경 중 status 결과를 바꿀 수 있는 것 (index, HEAD, 로컬 브랜치)
+fn affects_status(p: &Path) -> bool {
+    let is_index = p.file_name().map(|n| n == "index").unwrap_or(false)
+        && p.parent().and_then(|d| d.file_name()).map(|n| n == ".git").unwrap_or(false);
+    is_index || affects_head(p)
 }
 
 #[derive(Clone, serde::Serialize)]
@@ -17,22 +43,127 @@ pub struct GitChangeEvent {
     pub change_type: String,
 }
 
+#[derive(Clone, serde::Serialize)]
+pub struct GitHeadChangedEvent {
+    pub repo_path: String,
+    pub new_branch: String,
+    pub new_oid_short: String,
+}
+
+/// 현재 HEAD의 (브랜치 이름, short oid). detached면 브랜치 이름은 "HEAD", 빈 저장소면 None
+fn read_head_state(repo_path: &str) -> Option<(String, String)> {
+    let repo = git2::Repository::open(repo_path).ok()?;
+    let head = repo.head().ok()?;
+    let branch = if head.is_branch() {
+        head.shorthand().unwrap_or("HEAD").to_string()
+    } else {
+        "HEAD".to_string()
+    };
+    let oid = head.target()?.to_string()[..7].to_string();
+    Some((branch, oid))
+}
+
+/// HEAD가 움직일 수 있는 변경인지 (.git/HEAD 또는 .git/refs/heads/ 하위)
+fn affects_head(p: &Path) -> bool {
+    let is_head_file = p.file_name().map(|n| n == "HEAD").unwrap_or(false)
+        && p.parent().and_then(|d| d.file_name()).map(|n| n == ".git").unwrap_or(false);
+    let path_str = p.to_string_lossy();
+    is_head_file || path_str.contains("/.git/refs/heads/") || path_str.contains("\\.git\\refs\\heads\\")
+}
+
+#[derive(Clone, serde::Serialize)]
+pub struct GitStatusEvent {
+    pub repo_path: String,
+    pub files: Vec<crate::git::FileStatus>,
+}
+
+#[derive(Clone, serde::Serialize)]
+pub struct GitRemoteUpdatedEvent {
+    pub repo_path: String,
+}
+
+/// 원격 추적 ref가 바뀌는 변경인지 (.git/refs/remotes/ 하위 또는 .git/FETCH_HEAD)
+fn affects_remote_refs(p: &Path) -> bool {
+    let is_fetch_head = p.file_name().map(|n| n == "FETCH_HEAD").unwrap_or(false)
+        && p.parent().and_then(|d| d.file_name()).map(|n| n == ".git").unwrap_or(false);
+    let path_str = p.to_string_lossy();
+    is_fetch_head || path_str.contains("/.git/refs/remotes/") || path_str.contains("\\.git\\refs\\remotes\\")
+}
+
 #[tauri::command]
-pub fn watch_repo(app: AppHandle, path: String) -> Result<(), String> {
+pub fn watch_repo(
+    app: AppHandle,
+    path: String,
+    watch_remote_refs: Option<bool>,
+    emit_status: Option<bool>,
+) -> Result<(), String> {
     let mut watchers = WATCHERS.lock().map_err(|e| e.to_string())?;
 
-    // 이미 감시 중이면 스킵
+    // 이미 감시 중이면 옵션만 갱신
     if watchers.contains_key(&path) {
+        if let Some(enabled) = emit_status {
+            if let Some(flag) = EMIT_STATUS_FLAGS.lock().map_err(|e| e.to_string())?.get(&path) {
+                flag.store(enabled, Ordering::Relaxed);
+            }
+        }
+        if let Some(enabled) = watch_remote_refs {
+            set_watch_remote_refs(path, enabled)?;
+        }
         return Ok(());
     }
 
     let repo_path = path.clone();
     let app_handle = app.clone();
     let last_emit = Arc::new(Mutex::new(Instant::now()));
+    let last_head = Arc::new(Mutex::new(read_head_state(&path)));
+    let last_remote_emit = Arc::new(Mutex::new(Instant::now()));
+    let remote_flag = Arc::new(AtomicBool::new(watch_remote_refs.unwrap_or(false)));
+    REMOTE_REF_FLAGS
+        .lock()
+        .map_err(|e| e.to_string())?
+        .insert(path.clone(), remote_flag.clone());
+    STATUS_GENERATIONS
+        .lock()
+        .map_err(|e| e.to_string())?
+        .insert(path.clone(), 1);
+    let status_flag = Arc::new(AtomicBool::new(emit_status.unwrap_or(false)));
+    EMIT_STATUS_FLAGS
+        .lock()
+        .map_err(|e| e.to_string())?
+        .insert(path.clone(), status_flag.clone());
 
     let mut watcher = RecommendedWatcher::new(
         move |res: Result<Event, notify::Error>| {
             if let Ok(event) = res {
+                // 브랜치 전환, 커밋, reset 등으로 HEAD가 바뀌면 별도 이벤트로 알림
+                if event.paths.iter().any(|p| affects_head(p)) {
+                    let current = read_head_state(&repo_path);
+                    let mut last = last_head.lock().unwrap();
+                    if current != *last {
+                        if let Some((branch, oid)) = &current {
+                            let _ = app_handle.emit("git-head-changed", GitHeadChangedEvent {
+                                repo_path: repo_path.clone(),
+                                new_branch: branch.clone(),
+                                new_oid_short: oid.clone(),
+                            });
+                        }
+                        *last = current;
+                    }
+                }
+
+                // 백그라운드 fetch 등으로 원격 추적 ref가 바뀌면 알림 (옵션)
+                if remote_flag.load(Ordering::Relaxed)
+                    && event.paths.iter().any(|p| affects_remote_refs(p))
+                {
+                    let mut last = last_remote_emit.lock().unwrap();
+                    if last.elapsed() > Duration::from_millis(1000) {
+                        *last = Instant::now();
+                        let _ = app_handle.emit("git-remote-updated", GitRemoteUpdatedEvent {
+                            repo_path: repo_path.clone(),
+                        });
+                    }
+                }
+
                 // 불필요한 파일 변경 필터링
                 let dominated_paths: Vec<_> = event.paths.iter()
                     .filter(|p| {
@@ -57,6 +188,11 @@ pub fn watch_repo(app: AppHandle, path: String) -> Result<(), String> {
                     })
                     .collect();
 
+                // status가 바뀌었을 수 있으면 세대 번호 증가 (디바운싱 없이)
+                if !dominated_paths.is_empty() || event.paths.iter().any(|p| affects_status(p)) {
+                    bump_status_generation(&repo_path);
+                }
+
                 if !dominated_paths.is_empty() {
                     // 디바운싱: 1초 이내 중복 이벤트 무시
                     let mut last = last_emit.lock().unwrap();
@@ -74,6 +210,16 @@ pub fn watch_repo(app: AppHandle, path: String) -> Result<(), String> {
                             repo_path: repo_path.clone(),
                             change_type: change_type.to_string(),
                         });
+
+                        // 옵션: UI가 get_status를 다시 부르지 않도록 새 status를 같이 보냄
+                        if status_flag.load(Ordering::Relaxed) {
+                            if let Ok(files) = crate::git::get_status(&repo_path) {
+                                let _ = app_handle.emit("git-status", GitStatusEvent {
+                                    repo_path: repo_path.clone(),
+                                    files,
+                                });
+                            }
+                        }
                     }
                 }
             }
@@ -91,10 +237,25 @@ pub fn watch_repo(app: AppHandle, path: String) -> Result<(), String> {
     Ok(())
 }
 
+/// 감시 중인 저장소의 원격 ref 감시(git-remote-updated) 켜기/끄기
+#[tauri::command]
+pub fn set_watch_remote_refs(path: String, enabled: bool) -> Result<(), String> {
+    let flags = REMOTE_REF_FLAGS.lock().map_err(|e| e.to_string())?;
+    let flag = flags
+        .get(&path)
+        .ok_or_else(|| "감시 중인 저장소가 아닙니다".to_string())?;
+    flag.store(enabled, Ordering::Relaxed);
+    Ok(())
+}
+
 #[tauri::command]
 pub fn unwatch_repo(path: String) -> Result<(), String> {
     let mut watchers = WATCHERS.lock().map_err(|e| e.to_string())?;
     watchers.remove(&path);
+    REMOTE_REF_FLAGS.lock().map_err(|e| e.to_string())?.remove(&path);
+    STATUS_GENERATIONS.lock().map_err(|e| e.to_string())?.remove(&path);
+    EMIT_STATUS_FLAGS.lock().map_err(|e| e.to_string())?.remove(&path);
+    stop_auto_fetch(path)?;
     Ok(())
 }
 
@@ -102,5 +263,80 @@ pub fn unwatch_repo(path: String) -> Result<(), String> {
 pub fn unwatch_all() -> Result<(), String> {
     let mut watchers = WATCHERS.lock().map_err(|e| e.to_string())?;
     watchers.clear();
+    REMOTE_REF_FLAGS.lock().map_err(|e| e.to_string())?.clear();
+    STATUS_GENERATIONS.lock().map_err(|e| e.to_string())?.clear();
+    EMIT_STATUS_FLAGS.lock().map_err(|e| e.to_string())?.clear();
+    for (_, token) in AUTO_FETCH_TASKS.lock().map_err(|e| e.to_string())?.drain() {
+        token.cancel();
+    }
+    Ok(())
+}
+
+/// 다른 git 작업이 진행 중인지 (lock 파일 존재)
+fn has_active_lock(repo_path: &str) -> bool {
+    match git2::Repository::open(repo_path) {
+        Ok(repo) => crate::git::ACTIVE_LOCK_FILES
+            .iter()
+            .any(|f| repo.path().join(f).exists()),
+        Err(_) => true,
+    }
+}
+
+/// 주기적으로 `git fetch --all` 실행 후 git-remote-updated 이벤트 전송. 이미 실행 중이면 주기만 바꿔 다시 시작
+#[tauri::command]
+pub fn start_auto_fetch(app: AppHandle, path: String, interval_secs: u64) -> Result<(), String> {
+    if interval_secs < 30 {
+        return Err("자동 fetch 주기는 30초 이상이어야 합니다".to_string());
+    }
+
+    let token = CancellationToken::new();
+    if let Some(previous) = AUTO_FETCH_TASKS
+        .lock()
+        .map_err(|e| e.to_string())?
+        .insert(path.clone(), token.clone())
+    {
+        previous.cancel();
+    }
+
+    tauri::async_runtime::spawn(async move {
+        let interval = Duration::from_secs(interval_secs);
+        loop {
+            tokio::select! {
+                _ = token.cancelled() => break,
+                _ = tokio::time::sleep(interval) => {}
+            }
+
+            // 사용자가 실행한 작업과 겹치면 이번 주기는 건너뜀
+            if has_active_lock(&path) {
+                continue;
+            }
+
+            // 중지되면 실행 중인 fetch도 함께 종료
+            let fetch = tokio::process::Command::new("git")
+                .args(["fetch", "--all", "--quiet"])
+                .current_dir(&path)
+                .kill_on_drop(true)
+                .output();
+            let output = tokio::select! {
+                _ = token.cancelled() => break,
+                output = fetch => output,
+            };
+
+            if matches!(output, Ok(ref o) if o.status.success()) {
+                let _ = app.emit("git-remote-updated", GitRemoteUpdatedEvent {
+                    repo_path: path.clone(),
+                });
+            }
+        }
+    });
+
+    Ok(())
+}
+
+#[tauri::command]
+pub fn stop_auto_fetch(path: String) -> Result<(), String> {
+    if let Some(token) = AUTO_FETCH_TASKS.lock().map_err(|e| e.to_string())?.remove(&path) {
+        token.cancel();
+    }
     Ok(())
 }
@@ -10,6 +10,7 @@ import { Button } from '@/components/ui/button'
 import { Input } from '@/components/ui/input'
 import { Label } from '@/components/ui/label'
 import { RadioGroup, RadioGroupItem } from '@/components/ui/radio-group'
+import { Switch } from '@/components/ui/switch'
 import { Settings, Loader2, Check } from 'lucide-react'
 import { toast } from 'sonner'
 import { getAiConfig, saveAiConfig, type AiConfig } from '@/hooks/useTauriGit'
@@ -26,6 +27,11 @@ export function AiSettings() {
     openai_model: 'gpt-4o-mini',
     anthropic_key: '',
     anthropic_model: 'claude-3-5-haiku-latest',
+    azure_endpoint: '',
+    azure_deployment: '',
+    azure_key: '',
+    redact_secrets: true,
+    wrap_body: false,
   })
 
   useEffect(() => {
@@ -89,6 +95,10 @@ export function AiSettings() {
                   <RadioGroupItem value="anthropic" id="anthropic" />
                   <Label htmlFor="anthropic" className="text-xs cursor-pointer">Anthropic</Label>
                 </div>
+                <div className="flex items-center space-x-2">
+                  <RadioGroupItem value="azure" id="azure" />
+                  <Label htmlFor="azure" className="text-xs cursor-pointer">Azure</Label>
+                </div>
               </RadioGroup>
             </div>
 
@@ -169,6 +179,65 @@ export function AiSettings() {
               </div>
             )}
 
+            {/* Azure OpenAI 설정 */}
+            {config.provider === 'azure' && (
+              <div className="space-y-3 p-3 bg-muted/50 rounded-md">
+                <div className="space-y-1">
+                  <Label className="text-xs">엔드포인트</Label>
+                  <Input
+                    value={config.azure_endpoint}
+                    onChange={(e) => setConfig({ ...config, azure_endpoint: e.target.value })}
+                    placeholder="https://my-resource.openai.azure.com"
+                    className="h-8 text-xs"
+                  />
+                </div>
+                <div className="space-y-1">
+                  <Label className="text-xs">배포 이름</Label>
+                  <Input
+                    value={config.azure_deployment}
+                    onChange={(e) => setConfig({ ...config, azure_deployment: e.target.value })}
+                    placeholder="gpt-4o-mini"
+                    className="h-8 text-xs"
+                  />
+                </div>
+                <div className="space-y-1">
+                  <Label className="text-xs">API Key</Label>
+                  <Input
+                    type="password"
+                    value={config.azure_key}
+                    onChange={(e) => setConfig({ ...config, azure_key: e.target.value })}
+                    className="h-8 text-xs font-mono"
+                  />
+                </div>
+              </div>
+            )}
+
+            {/* 클라우드 제공자 전송 시 비밀값 가리기 */}
+            {config.provider !== 'ollama' && (
+              <div className="flex items-center justify-between gap-2">
+                <Label htmlFor="redact-secrets" className="text-xs cursor-pointer">
+                  전송 전 diff의 비밀값(API 키, 토큰, 비밀번호) 가리기
+                </Label>
+                <Switch
+                  id="redact-secrets"
+                  checked={config.redact_secrets}
+                  onCheckedChange={(checked) => setConfig({ ...config, redact_secrets: checked })}
+                />
+              </div>
+            )}
+
+            {/* 본문 줄바꿈 */}
+            <div className="flex items-center justify-between gap-2">
+              <Label htmlFor="wrap-body" className="text-xs cursor-pointer">
+                생성된 메시지 본문을 72자에 맞춰 줄바꿈
+              </Label>
+              <Switch
+                id="wrap-body"
+                checked={config.wrap_body}
+                onCheckedChange={(checked) => setConfig({ ...config, wrap_body: checked })}
+              />
+            </div>
+
             {/* 저장 버튼 */}
             <Button
               onClick={handleSave}
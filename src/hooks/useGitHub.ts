@@ -44,6 +44,29 @@ export async function deleteGitHubToken(): Promise<void> {
   return invoke('delete_github_token')
 }
 
+// 여러 계정 (토큰 관련 함수는 활성 계정 기준으로 동작)
+export interface GitHubAccount {
+  name: string
+  login: string | null
+  active: boolean
+}
+
+export async function addGitHubAccount(name: string, token: string): Promise<GitHubAccount> {
+  return invoke('add_github_account', { name, token })
+}
+
+export async function listGitHubAccounts(): Promise<GitHubAccount[]> {
+  return invoke('list_github_accounts')
+}
+
+export async function setActiveGitHubAccount(name: string): Promise<void> {
+  return invoke('set_active_github_account', { name })
+}
+
+export async function deleteGitHubAccount(name: string): Promise<void> {
+  return invoke('delete_github_account', { name })
+}
+
 // GitHub API
 export async function fetchGitHubUser(token: string): Promise<GitHubUser> {
   return invoke('fetch_github_user', { token })
@@ -67,16 +90,141 @@ export async function removeGitHubFavorite(repoId: number): Promise<void> {
 }
 
 // 저장소 생성
+// autoInit이 true면 GitHub에서 초기 커밋을 만들므로 push 대신 clone_url로 clone해야 함
 export async function createGitHubRepo(
   token: string,
   name: string,
   description: string | null,
-  isPrivate: boolean
+  isPrivate: boolean,
+  autoInit = false,
+  gitignoreTemplate: string | null = null,
+  licenseTemplate: string | null = null
 ): Promise<GitHubRepo> {
   return invoke('create_github_repo', {
     token,
     name,
     description,
     private: isPrivate,
+    autoInit,
+    gitignoreTemplate,
+    licenseTemplate,
   })
 }
+
+// CI 상태
+export interface CommitStatusItem {
+  context: string
+  state: string
+  target_url: string | null
+}
+
+export interface CombinedStatus {
+  state: 'success' | 'failure' | 'pending' | string
+  total_count: number
+  statuses: CommitStatusItem[]
+}
+
+export async function fetchGitHubCommitStatus(
+  token: string,
+  owner: string,
+  repo: string,
+  gitRef: string
+): Promise<CombinedStatus> {
+  return invoke('fetch_github_commit_status', { token, owner, repo, gitRef })
+}
+
+// Pull Request
+export interface PullRequestBranch {
+  label: string
+  ref: string
+}
+
+export interface PullRequest {
+  number: number
+  title: string
+  html_url: string
+  state: string
+  draft: boolean
+  head: PullRequestBranch
+  base: PullRequestBranch
+}
+
+// headBranch에 "owner:branch"를 넘기면 fork 브랜치의 PR도 찾을 수 있음
+export async function findPullForBranch(
+  token: string,
+  owner: string,
+  repo: string,
+  headBranch: string
+): Promise<PullRequest | null> {
+  return invoke('find_pull_for_branch', { token, owner, repo, headBranch })
+}
+
+// 언어 구성 / 토픽
+export interface LanguageStat {
+  name: string
+  bytes: number
+}
+
+export interface RepoMetadata {
+  languages: LanguageStat[] // 바이트 수 내림차순
+  topics: string[]
+}
+
+export async function fetchGitHubRepoMetadata(token: string, owner: string, repo: string): Promise<RepoMetadata> {
+  return invoke('fetch_github_repo_metadata', { token, owner, repo })
+}
+
+// README 원문 markdown (없으면 "README가 없습니다" 에러)
+export async function fetchGitHubReadme(token: string, owner: string, repo: string): Promise<string> {
+  return invoke('fetch_github_readme', { token, owner, repo })
+}
+
+// .gitignore 템플릿 (GitHub 공개 API, 로컬 캐시)
+export async function listGitignoreTemplates(): Promise<string[]> {
+  return invoke('list_gitignore_templates')
+}
+
+// 저장소의 .gitignore에 템플릿 규칙 추가 (이미 있는 규칙은 건너뜀)
+export async function applyGitignoreTemplate(path: string, template: string): Promise<void> {
+  return invoke('apply_gitignore_template', { path, template })
+}
+
+// Gist
+export interface Gist {
+  id: string
+  description: string | null
+  files: string[]
+  public: boolean
+  html_url: string
+  git_pull_url: string
+  updated_at: string
+}
+
+export async function fetchGitHubGists(token: string): Promise<Gist[]> {
+  return invoke('fetch_github_gists', { token })
+}
+
+export async function cloneGist(gitPullUrl: string, path: string): Promise<void> {
+  return invoke('clone_gist', { gitPullUrl, path })
+}
+
+// 워크플로별 최신 GitHub Actions 실행 (최신순, Actions가 꺼진 저장소는 빈 배열)
+export interface WorkflowRun {
+  id: number
+  workflow_id: number
+  name: string | null
+  status: string | null // queued, in_progress, completed 등
+  conclusion: string | null // 완료 전에는 null
+  head_sha: string
+  html_url: string
+  created_at: string
+}
+
+export async function fetchGitHubWorkflowRuns(
+  token: string,
+  owner: string,
+  repo: string,
+  branch?: string
+): Promise<WorkflowRun[]> {
+  return invoke('fetch_github_workflow_runs', { token, owner, repo, branch })
+}
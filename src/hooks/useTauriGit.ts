@@ -20,12 +20,22 @@ export interface FileStatus {
   path: string
   status: 'modified' | 'added' | 'deleted' | 'renamed' | 'untracked'
   staged: boolean
+  is_lfs: boolean
+}
+
+export interface CleanState {
+  clean: boolean
+  staged: number
+  unstaged: number
+  untracked: number
 }
 
 export interface CommitInfo {
   hash: string
   hash_short: string
   message: string
+  // 메시지가 UTF-8이 아니어서 인코딩을 추정해 디코딩한 경우 true
+  message_lossy: boolean
   author: string
   email: string
   date: string
@@ -35,6 +45,7 @@ export interface GraphCommit {
   hash: string
   hash_short: string
   message: string
+  message_lossy: boolean
   author: string
   email: string
   date: string
@@ -45,6 +56,12 @@ export interface GraphCommit {
   color: number
 }
 
+export interface BranchNameCheck {
+  valid: boolean
+  reason: string | null
+  exists: boolean
+}
+
 export interface RemoteStatus {
   ahead: number
   behind: number
@@ -52,6 +69,13 @@ export interface RemoteStatus {
   remote: string | null
 }
 
+// 저장소가 아닌 경로에서 명령을 호출했을 때 Rust 쪽 에러 메시지 접두사
+export const NOT_A_REPO_PREFIX = 'NOT_A_REPO:'
+
+export function isNotARepoError(error: unknown): boolean {
+  return String(error).startsWith(NOT_A_REPO_PREFIX)
+}
+
 // Convert snake_case from Rust to camelCase for frontend
 function convertRepoInfo(data: any): RepoInfo {
   return {
@@ -77,10 +101,64 @@ export async function getRepoInfo(path: string): Promise<RepoInfo> {
   return convertRepoInfo(data)
 }
 
+export interface RepoCommitState {
+  is_empty: boolean // 아직 커밋이 없는 저장소
+  commit_count: number
+  count_capped: boolean // 1000개를 넘어 세기를 멈춤
+}
+
+export async function getRepoCommitState(path: string): Promise<RepoCommitState> {
+  return invoke<RepoCommitState>('get_repo_commit_state', { path })
+}
+
 export async function getStatus(path: string): Promise<FileStatus[]> {
   return invoke<FileStatus[]>('get_status', { path })
 }
 
+export interface StatusSnapshot {
+  generation: number
+  files: FileStatus[]
+}
+
+// 마지막으로 받은 generation 이후 변경이 없으면 null
+export async function getStatusIfChanged(path: string, lastGeneration: number): Promise<StatusSnapshot | null> {
+  return invoke<StatusSnapshot | null>('get_status_if_changed', { path, lastGeneration })
+}
+
+export async function isWorkingTreeClean(path: string): Promise<CleanState> {
+  return invoke<CleanState>('is_working_tree_clean', { path })
+}
+
+export interface IndexEntry {
+  path: string
+  stage: number
+  mode: string
+  oid_short: string
+  size: number
+}
+
+export async function listIndexEntries(path: string, prefix?: string): Promise<IndexEntry[]> {
+  return invoke<IndexEntry[]>('list_index_entries', { path, prefix })
+}
+
+export interface TreeEntryWithCommit {
+  name: string
+  is_dir: boolean
+  last_commit_short: string
+  last_commit_summary: string
+  last_commit_date: string
+}
+
+// HEAD 기준 디렉토리 목록 + 항목별 마지막 커밋 (디렉토리 먼저, 이름순)
+export async function listTreeWithLastCommit(path: string, subdir?: string): Promise<TreeEntryWithCommit[]> {
+  return invoke<TreeEntryWithCommit[]>('list_tree_with_last_commit', { path, subdir })
+}
+
+// .gitattributes 기준 Git LFS 추적 여부 (filter=lfs)
+export async function isLfsTracked(path: string, filePath: string): Promise<boolean> {
+  return invoke<boolean>('is_lfs_tracked', { path, filePath })
+}
+
 export async function stageFile(path: string, filePath: string): Promise<void> {
   return invoke('stage_file', { path, filePath })
 }
@@ -93,16 +171,79 @@ export async function stageAll(path: string): Promise<void> {
   return invoke('stage_all', { path })
 }
 
-export async function commit(path: string, message: string): Promise<string> {
-  return invoke<string>('commit', { path, message })
+// git add -N: 내용은 스테이징하지 않고 diff에 새 파일로 표시
+export async function intentToAdd(path: string, filePath: string): Promise<void> {
+  return invoke('intent_to_add', { path, filePath })
+}
+
+export interface MessageLint {
+  subject_length: number
+  subject_over_limit: boolean // 50자 초과
+  has_body: boolean
+  body_lines_over: number[] // 72자를 넘는 줄 번호 (1부터)
+  blank_line_after_subject: boolean
+}
+
+export async function validateCommitMessage(message: string): Promise<MessageLint> {
+  return invoke<MessageLint>('validate_commit_message', { message })
+}
+
+// noVerify: 훅(pre-commit, commit-msg 등)을 건너뛰고 커밋
+export async function commit(path: string, message: string, noVerify: boolean = false): Promise<string> {
+  return invoke<string>('commit', { path, message, noVerify })
+}
+
+// 작성자와 커미터를 따로 지정 (생략한 값은 user.name/user.email 사용)
+export async function commitFull(
+  path: string,
+  message: string,
+  authorName?: string,
+  authorEmail?: string,
+  committerName?: string,
+  committerEmail?: string,
+  noVerify: boolean = false
+): Promise<string> {
+  return invoke<string>('commit_full', {
+    path,
+    message,
+    noVerify,
+    authorName,
+    authorEmail,
+    committerName,
+    committerEmail,
+  })
+}
+
+export async function quickCommit(path: string, message: string, includeUntracked: boolean): Promise<string> {
+  return invoke<string>('quick_commit', { path, message, includeUntracked })
 }
 
-export async function push(path: string): Promise<void> {
-  return invoke('push', { path })
+// 선택한 hunk patch들만 인덱스에 적용해서 커밋 (작업 트리는 그대로 유지, 이미 스테이징된 변경이 있으면 거부)
+export async function commitPartial(path: string, patches: string[], message: string): Promise<string> {
+  return invoke<string>('commit_partial', { path, patches, message })
 }
 
-export async function pushToRemote(path: string, remote: string, branch: string): Promise<void> {
-  return invoke('push_to_remote', { path, remote, branch })
+// noVerify: pre-push 훅을 건너뛰고 push
+export async function push(path: string, noVerify: boolean = false): Promise<void> {
+  return invoke('push', { path, noVerify })
+}
+
+export async function pushToRemote(path: string, remote: string, branch: string, noVerify: boolean = false): Promise<void> {
+  return invoke('push_to_remote', { path, remote, branch, noVerify })
+}
+
+// 새 브랜치 첫 push (push -u). remote 생략 시 첫 번째 remote, branch 생략 시 현재 브랜치
+export async function pushNewBranch(path: string, remote?: string, branch?: string): Promise<void> {
+  return invoke('push_new_branch', { path, remote, branch })
+}
+
+export interface PushPreview {
+  updates: string[]
+  non_fast_forward: boolean
+}
+
+export async function pushDryRun(path: string, remote?: string, branch?: string): Promise<PushPreview> {
+  return invoke<PushPreview>('push_dry_run', { path, remote, branch })
 }
 
 export async function pull(path: string): Promise<void> {
@@ -117,8 +258,67 @@ export async function getBranches(path: string): Promise<BranchInfo[]> {
   return invoke<BranchInfo[]>('get_branches', { path })
 }
 
-export async function checkoutBranch(path: string, branchName: string): Promise<void> {
-  return invoke('checkout_branch', { path, branchName })
+// 다른 worktree에서 체크아웃 중인 브랜치를 제외한 목록
+export async function getAvailableBranches(path: string): Promise<BranchInfo[]> {
+  return invoke<BranchInfo[]>('get_available_branches', { path })
+}
+
+export type CheckoutStrategy = 'safe' | 'stash' | 'force'
+
+export async function checkoutBranch(path: string, branchName: string, strategy: CheckoutStrategy = 'safe'): Promise<void> {
+  return invoke('checkout_branch', { path, branchName, strategy })
+}
+
+export interface DeleteSafety {
+  merged: boolean
+  is_current: boolean
+  has_upstream_gone: boolean
+  unpushed_commits: number // 삭제 시 잃게 되는 커밋 수
+}
+
+export async function canDeleteBranch(path: string, branchName: string): Promise<DeleteSafety> {
+  return invoke<DeleteSafety>('can_delete_branch', { path, branchName })
+}
+
+// 기본 브랜치(origin/HEAD, 없으면 main/master)에 있는지. detached/빈 저장소면 에러
+export async function isOnDefaultBranch(path: string): Promise<boolean> {
+  return invoke<boolean>('is_on_default_branch', { path })
+}
+
+export interface AheadBehind {
+  base_branch: string // 예: "origin/main", "main"
+  ahead: number
+  behind: number
+}
+
+// 기본 브랜치 대비 앞선/뒤처진 커밋 수 (로컬 ref 기준, fetch 하지 않음)
+export async function branchVsDefault(path: string): Promise<AheadBehind> {
+  return invoke<AheadBehind>('branch_vs_default', { path })
+}
+
+export interface MigrationStep {
+  step: string
+  success: boolean
+  message: string
+}
+
+export interface BranchMigration {
+  steps: MigrationStep[]
+  completed: boolean // false면 마지막 단계에서 실패하고 멈춤
+}
+
+// 예: migrateDefaultBranch(path, 'master', 'main', 'origin')
+export async function migrateDefaultBranch(
+  path: string,
+  oldName: string,
+  newName: string,
+  remote: string = 'origin'
+): Promise<BranchMigration> {
+  return invoke<BranchMigration>('migrate_default_branch', { path, oldName, newName, remote })
+}
+
+export async function getRecentBranches(path: string, maxCount: number = 10): Promise<string[]> {
+  return invoke<string[]>('get_recent_branches', { path, maxCount })
 }
 
 export async function getLog(path: string, maxCount: number): Promise<CommitInfo[]> {
@@ -129,28 +329,297 @@ export async function getGraphLog(path: string, maxCount: number): Promise<Graph
   return invoke<GraphCommit[]>('get_graph_log', { path, maxCount })
 }
 
-export async function getDiff(path: string, filePath?: string): Promise<string> {
-  return invoke<string>('get_diff', { path, filePath })
+export async function getCommitParents(path: string, commitHash: string): Promise<string[]> {
+  return invoke<string[]>('get_commit_parents', { path, commitHash })
+}
+
+// lane은 계산하지 않음 (column/color = 0)
+export async function getCommitsBatch(path: string, hashes: string[]): Promise<GraphCommit[]> {
+  return invoke<GraphCommit[]>('get_commits_batch', { path, hashes })
+}
+
+// Graphviz DOT 텍스트 (dot -Tsvg 등으로 렌더링)
+export async function exportGraphDot(path: string, maxCount: number): Promise<string> {
+  return invoke<string>('export_graph_dot', { path, maxCount })
+}
+
+export async function getUnpushedCommits(path: string): Promise<CommitInfo[]> {
+  return invoke<CommitInfo[]>('get_unpushed_commits', { path })
 }
 
-export async function getStagedDiff(path: string): Promise<string> {
-  return invoke<string>('get_staged_diff', { path })
+export async function getUnpulledCommits(path: string): Promise<CommitInfo[]> {
+  return invoke<CommitInfo[]>('get_unpulled_commits', { path })
 }
 
-export async function getCommitDiff(path: string, commitHash: string): Promise<string> {
-  return invoke<string>('get_commit_diff', { path, commitHash })
+export async function resolveRef(path: string, refName: string): Promise<CommitInfo> {
+  return invoke<CommitInfo>('resolve_ref', { path, refName })
+}
+
+// HEAD~3, @{u}, 브랜치 이름 등을 전체 sha로
+export async function revParse(path: string, expr: string): Promise<string> {
+  return invoke<string>('rev_parse', { path, expr })
+}
+
+// commitHash를 생략하면 HEAD 기준 (예: v1.2.0-5-gabc1234)
+export async function describeCommit(path: string, commitHash?: string, tags = true): Promise<string> {
+  return invoke<string>('describe_commit', { path, commitHash, tags })
+}
+
+export async function getCommitNotes(path: string, commitHash: string): Promise<string | null> {
+  return invoke<string | null>('get_commit_notes', { path, commitHash })
+}
+
+// 빈 문자열을 넘기면 notes 삭제
+export async function setCommitNotes(path: string, commitHash: string, note: string): Promise<void> {
+  return invoke('set_commit_notes', { path, commitHash, note })
+}
+
+export type WhitespaceMode = 'show' | 'ignore-all' | 'ignore-eol'
+
+export async function getDiff(path: string, filePath?: string, whitespace: WhitespaceMode = 'show'): Promise<string> {
+  return invoke<string>('get_diff', { path, filePath, whitespace })
+}
+
+// 여러 파일/폴더(예: "src/")의 변경을 하나의 patch로
+export async function getPathsDiff(path: string, paths: string[], whitespace: WhitespaceMode = 'show'): Promise<string> {
+  return invoke<string>('get_diff', { path, paths, whitespace })
+}
+
+export async function getStagedDiff(path: string, whitespace: WhitespaceMode = 'show'): Promise<string> {
+  return invoke<string>('get_staged_diff', { path, whitespace })
+}
+
+export async function getCommitDiff(path: string, commitHash: string, whitespace: WhitespaceMode = 'show'): Promise<string> {
+  return invoke<string>('get_commit_diff', { path, commitHash, whitespace })
+}
+
+export interface CommitStats {
+  files_changed: number
+  insertions: number
+  deletions: number
+}
+
+export async function getCommitStats(path: string, commitHash: string): Promise<CommitStats> {
+  return invoke<CommitStats>('get_commit_stats', { path, commitHash })
+}
+
+// staged: index vs HEAD, 아니면 작업 트리 vs index (추적하지 않는 파일 제외)
+export async function getWorktreeDiffStat(path: string, staged: boolean): Promise<CommitStats> {
+  return invoke<CommitStats>('get_worktree_diff_stat', { path, staged })
+}
+
+export interface DiffEntry {
+  path: string
+  old_path: string | null
+  status: 'added' | 'deleted' | 'modified' | 'renamed' | 'copied' | 'typechange'
+  similarity: number | null
+}
+
+// renameThreshold: 0-100 (기본 50, git -M과 동일)
+export async function compareCommits(
+  path: string,
+  a: string,
+  b: string,
+  findRenames: boolean = true,
+  renameThreshold?: number
+): Promise<DiffEntry[]> {
+  return invoke<DiffEntry[]>('compare_commits', { path, a, b, findRenames, renameThreshold })
+}
+
+export async function compareCommitsPatch(
+  path: string,
+  a: string,
+  b: string,
+  findRenames: boolean = true,
+  renameThreshold?: number
+): Promise<string> {
+  return invoke<string>('compare_commits_patch', { path, a, b, findRenames, renameThreshold })
+}
+
+export interface Divergence {
+  left_only: CommitInfo[]
+  right_only: CommitInfo[]
+  base: CommitInfo | null
+}
+
+// right를 생략하면 현재 브랜치의 upstream과 비교
+export async function getDivergence(path: string, left: string, right?: string): Promise<Divergence> {
+  return invoke<Divergence>('get_divergence', { path, left, right })
+}
+
+// 현재 브랜치에 branchName을 병합할 때 충돌이 날 파일 (실제 병합은 하지 않음)
+export async function previewMergeConflicts(path: string, branchName: string): Promise<string[]> {
+  return invoke<string[]>('preview_merge_conflicts', { path, branchName })
+}
+
+export interface ContainedRefs {
+  branches: string[]
+  tags: string[]
+}
+
+export async function commitContainedIn(path: string, commitHash: string): Promise<ContainedRefs> {
+  return invoke<ContainedRefs>('commit_contained_in', { path, commitHash })
+}
+
+export interface BlameLine {
+  line: number
+  content: string
+  hash: string
+  hash_short: string
+  author: string
+  email: string
+  date: string
+  summary: string
+  boundary: boolean
+}
+
+export interface BlameOptions {
+  ignoreRevs?: string[] // 포맷팅 커밋 등 건너뛸 커밋
+  newestCommit?: string
+  oldestCommit?: string
+}
+
+export async function getBlame(path: string, filePath: string, options: BlameOptions = {}): Promise<BlameLine[]> {
+  return invoke<BlameLine[]>('get_blame', { path, filePath, ...options })
+}
+
+export interface AuthorShare {
+  author: string
+  email: string
+  lines: number
+  percent: number // 0-100
+}
+
+// 작성자별 줄 수/비율 (줄 수 내림차순, 빈 파일이면 빈 배열)
+export async function getBlameSummary(path: string, filePath: string): Promise<AuthorShare[]> {
+  return invoke<AuthorShare[]>('get_blame_summary', { path, filePath })
+}
+
+export interface UndoResult {
+  operation: string // 예: "commit", "merge feature", "rebase"
+  restored_short: string
+}
+
+// 마지막 HEAD 이동을 reflog로 되돌림. checkout은 거부, rebase는 시작 전 위치로
+// 변경사항이 있으면 force일 때만 HEAD만 이동(soft)
+export async function undoLast(path: string, force: boolean = false): Promise<UndoResult> {
+  return invoke<UndoResult>('undo_last', { path, force })
 }
 
 export async function discardChanges(path: string, filePath: string): Promise<void> {
   return invoke('discard_changes', { path, filePath })
 }
 
+export async function rewordCommit(path: string, commitHash: string, newMessage: string, force = false): Promise<void> {
+  return invoke('reword_commit', { path, commitHash, newMessage, force })
+}
+
+// date는 RFC 3339 형식 (예: 2024-01-31T12:00:00+09:00)
+export async function amendCommitMeta(
+  path: string,
+  authorName?: string,
+  authorEmail?: string,
+  date?: string
+): Promise<string> {
+  return invoke<string>('amend_commit_meta', { path, authorName, authorEmail, date })
+}
+
+// 병합 커밋이면 mainline(기준 부모 번호, 1부터) 필수, 일반 커밋이면 무시
+export async function cherryPickWithMainline(path: string, commitHash: string, mainline?: number): Promise<void> {
+  return invoke('cherry_pick_with_mainline', { path, commitHash, mainline })
+}
+
+export async function checkBranchName(path: string, name: string): Promise<BranchNameCheck> {
+  return invoke<BranchNameCheck>('check_branch_name', { path, name })
+}
+
+export interface StashDetail {
+  index: number
+  message: string
+  oid_short: string
+  time: number
+}
+
+export async function stashEntries(path: string): Promise<StashDetail[]> {
+  return invoke<StashDetail[]>('stash_entries', { path })
+}
+
+// 스테이징하지 않은 변경만 stash (index 유지, git stash push --keep-index)
+export async function stashUnstaged(path: string, includeUntracked: boolean): Promise<void> {
+  return invoke('stash_unstaged', { path, includeUntracked })
+}
+
+export async function stashBranch(path: string, index: number, branchName: string): Promise<void> {
+  return invoke('stash_branch', { path, index, branchName })
+}
+
+// 커밋하지 않은 변경을 그대로 가지고 현재 HEAD에서 새 브랜치로 이동 (git checkout -b)
+export async function moveChangesToBranch(path: string, newBranch: string): Promise<void> {
+  return invoke('move_changes_to_branch', { path, newBranch })
+}
+
+export interface StashPopPreview {
+  would_conflict: boolean
+  conflicting_paths: string[]
+}
+
+// pop 전에 충돌 여부 확인 (작업 트리는 변경하지 않음)
+export async function canPopStash(path: string, index: number): Promise<StashPopPreview> {
+  return invoke<StashPopPreview>('can_pop_stash', { path, index })
+}
+
+export interface SequencerStatus {
+  operation: 'none' | 'rebase' | 'am' | 'cherry-pick' | 'revert' | 'merge'
+  current_step: number
+  total_steps: number
+  current_commit_short: string | null
+  stopped_reason: 'conflict' | 'edit' | null
+}
+
+export async function getSequencerStatus(path: string): Promise<SequencerStatus> {
+  return invoke<SequencerStatus>('get_sequencer_status', { path })
+}
+
+export interface RebaseConflictContext {
+  current_commit: CommitInfo
+  step: number
+  total: number
+  conflicts: string[]
+}
+
+// 충돌로 멈춘 rebase의 현재 커밋/단계/충돌 파일. rebase 중이 아니면 에러
+export async function getRebaseConflictContext(path: string): Promise<RebaseConflictContext> {
+  return invoke<RebaseConflictContext>('get_rebase_conflict_context', { path })
+}
+
 export async function getRemoteStatus(path: string): Promise<RemoteStatus> {
   return invoke<RemoteStatus>('get_remote_status', { path })
 }
 
-export async function watchRepo(path: string): Promise<void> {
-  return invoke('watch_repo', { path })
+// 'git-head-changed' 이벤트 payload
+export interface GitHeadChangedEvent {
+  repo_path: string
+  new_branch: string
+  new_oid_short: string
+}
+
+// 'git-remote-updated' 이벤트 payload
+export interface GitRemoteUpdatedEvent {
+  repo_path: string
+}
+
+// 'git-status' 이벤트 payload (watchRepo의 emitStatus 옵션)
+export interface GitStatusEvent {
+  repo_path: string
+  files: FileStatus[]
+}
+
+export async function watchRepo(path: string, watchRemoteRefs?: boolean, emitStatus?: boolean): Promise<void> {
+  return invoke('watch_repo', { path, watchRemoteRefs, emitStatus })
+}
+
+export async function setWatchRemoteRefs(path: string, enabled: boolean): Promise<void> {
+  return invoke('set_watch_remote_refs', { path, enabled })
 }
 
 export async function unwatchRepo(path: string): Promise<void> {
@@ -161,12 +630,23 @@ export async function unwatchAll(): Promise<void> {
   return invoke('unwatch_all')
 }
 
+// intervalSecs마다 git fetch --all 후 'git-remote-updated' 이벤트 (30초 이상, unwatchRepo 시 함께 중지)
+export async function startAutoFetch(path: string, intervalSecs: number): Promise<void> {
+  return invoke('start_auto_fetch', { path, intervalSecs })
+}
+
+export async function stopAutoFetch(path: string): Promise<void> {
+  return invoke('stop_auto_fetch', { path })
+}
+
 // ============ 원격 저장소 관리 ============
 
 export interface RemoteInfo {
   name: string
   fetch_url: string
   push_url: string
+  branch_count: number
+  default_branch: string | null
 }
 
 export interface RemoteBranchInfo {
@@ -196,6 +676,40 @@ export async function renameRemote(path: string, oldName: string, newName: strin
   return invoke('rename_remote', { path, oldName, newName })
 }
 
+export async function getRemoteRefspecs(path: string, remote: string): Promise<string[]> {
+  return invoke<string[]>('get_remote_refspecs', { path, remote })
+}
+
+export async function setRemoteRefspec(path: string, remote: string, refspec: string, isPush: boolean): Promise<void> {
+  return invoke('set_remote_refspec', { path, remote, refspec, isPush })
+}
+
+export interface GitHubSlug {
+  owner: string
+  repo: string
+}
+
+// 원격(기본값 origin)이 github.com이 아니면 null
+export async function parseGitHubRemote(path: string, remote?: string): Promise<GitHubSlug | null> {
+  return invoke<GitHubSlug | null>('parse_github_remote', { path, remote })
+}
+
+export interface RemoteAccess {
+  reachable: boolean
+  authenticated: boolean
+  branch_count: number
+  error: string | null // 인증 정보는 제거됨
+}
+
+// push/pull 전 원격 접속/인증 확인 (git ls-remote --heads, 기본값 origin)
+export async function checkRemoteAccess(path: string, remote?: string): Promise<RemoteAccess> {
+  return invoke<RemoteAccess>('check_remote_access', { path, remote })
+}
+
+export async function githubPermalink(path: string, filePath: string, line?: number): Promise<string> {
+  return invoke<string>('github_permalink', { path, filePath, line })
+}
+
 export async function getRemoteBranches(path: string): Promise<RemoteBranchInfo[]> {
   return invoke<RemoteBranchInfo[]>('get_remote_branches', { path })
 }
@@ -204,6 +718,10 @@ export async function checkoutRemoteBranch(path: string, remoteBranch: string, l
   return invoke('checkout_remote_branch', { path, remoteBranch, localName })
 }
 
+export async function checkoutPullRequest(path: string, remote: string, prNumber: number, localName: string): Promise<void> {
+  return invoke('checkout_pull_request', { path, remote, prNumber, localName })
+}
+
 export async function deleteRemoteBranch(path: string, remote: string, branch: string): Promise<void> {
   return invoke('delete_remote_branch', { path, remote, branch })
 }
@@ -212,6 +730,11 @@ export async function pruneRemote(path: string, remote: string): Promise<void> {
   return invoke('prune_remote', { path, remote })
 }
 
+// pruneRemote로 삭제될 원격 추적 브랜치 (확인용, 실제로 삭제하지 않음)
+export async function findStaleRemoteBranches(path: string, remote: string): Promise<string[]> {
+  return invoke<string[]>('find_stale_remote_branches', { path, remote })
+}
+
 export async function fetchFromRemote(path: string, remote: string): Promise<void> {
   return invoke('fetch_from_remote', { path, remote })
 }
@@ -219,13 +742,20 @@ export async function fetchFromRemote(path: string, remote: string): Promise<voi
 // ============ AI 커밋 메시지 생성 ============
 
 export interface AiConfig {
-  provider: 'ollama' | 'openai' | 'anthropic'
+  provider: 'ollama' | 'openai' | 'anthropic' | 'azure'
   ollama_url: string
   ollama_model: string
   openai_key: string
   openai_model: string
   anthropic_key: string
   anthropic_model: string
+  azure_endpoint: string
+  azure_deployment: string
+  azure_key: string
+  // openai/anthropic/azure로 보낼 때 diff의 비밀값을 가림 (Ollama는 제외)
+  redact_secrets: boolean
+  // 생성된 메시지 본문을 72자에 맞춰 줄바꿈
+  wrap_body: boolean
 }
 
 export async function getAiConfig(): Promise<AiConfig> {
@@ -236,16 +766,270 @@ export async function saveAiConfig(config: AiConfig): Promise<void> {
   return invoke('save_ai_config', { config })
 }
 
-export async function generateCommitMessage(path: string): Promise<string> {
-  return invoke<string>('generate_commit_message', { path })
+// 저장소 루트의 .git-manager.json이 전역 설정의 일부 필드(모델 이름, wrap_body)를 덮어씀
+export interface EffectiveAiConfig {
+  config: AiConfig
+  overridden_fields: string[]
+  ignored_fields: string[] // provider, 주소, 키, redact_secrets 등 저장소에서 바꿀 수 없는 필드
+  override_file: string | null
+}
+
+export async function getEffectiveAiConfig(path: string): Promise<EffectiveAiConfig> {
+  return invoke<EffectiveAiConfig>('get_effective_ai_config', { path })
+}
+
+// requestId를 넘기면 cancelAiGeneration(requestId)로 생성을 취소할 수 있음
+export async function generateCommitMessage(path: string, requestId?: string): Promise<string> {
+  return invoke<string>('generate_commit_message', { path, requestId })
+}
+
+export interface PromptPreview {
+  prompt: string
+  diff_bytes: number
+  truncated: boolean
+  provider: string
+  model: string
+}
+
+// 실제 AI 호출 없이 전송될 프롬프트 확인
+export async function previewAiPrompt(path: string, includeUnstaged = false): Promise<PromptPreview> {
+  return invoke<PromptPreview>('preview_ai_prompt', { path, includeUnstaged })
+}
+
+export async function cancelAiGeneration(requestId: string): Promise<void> {
+  return invoke('cancel_ai_generation', { requestId })
+}
+
+export async function listOllamaModels(ollamaUrl: string): Promise<string[]> {
+  return invoke<string[]>('list_ollama_models', { ollamaUrl })
+}
+
+export async function listOpenAiModels(key: string): Promise<string[]> {
+  return invoke<string[]>('list_openai_models', { key })
+}
+
+export async function listAnthropicModels(key: string): Promise<string[]> {
+  return invoke<string[]>('list_anthropic_models', { key })
 }
 
 // ============ 저장소 초기화 및 복제 ============
 
-export async function initRepo(path: string): Promise<string> {
-  return invoke<string>('init_repo', { path })
+export async function initRepo(path: string, initialBranch?: string): Promise<string> {
+  return invoke<string>('init_repo', { path, initialBranch })
+}
+
+export async function initAndFirstCommit(path: string, message: string, initialBranch?: string): Promise<string> {
+  return invoke<string>('init_and_first_commit', { path, message, initialBranch })
 }
 
 export async function cloneRepo(url: string, path: string): Promise<void> {
   return invoke('clone_repo', { url, path })
 }
+
+// ============ 저장소 상태 점검 ============
+
+export interface RepoStats {
+  object_count: number
+  pack_count: number
+  size_bytes: number
+  loose_object_count: number
+}
+
+export async function getRepoStats(path: string): Promise<RepoStats> {
+  return invoke<RepoStats>('get_repo_stats', { path })
+}
+
+// `git-progress`(gc), `git-output`(pull/fetch/merge/rebase) 이벤트 payload
+export interface GitProgressEvent {
+  repo_path: string
+  operation: string
+  line: string
+}
+
+export async function runGc(path: string, aggressive: boolean): Promise<void> {
+  return invoke('run_gc', { path, aggressive })
+}
+
+export interface LockFile {
+  path: string // .git 기준 상대 경로
+  age_secs: number
+}
+
+export async function checkGitLocks(path: string): Promise<LockFile[]> {
+  return invoke<LockFile[]>('check_git_locks', { path })
+}
+
+// 오래된 lock 파일 제거 (사용자가 명시적으로 선택했을 때만 호출)
+export async function clearGitLock(path: string, lockPath: string): Promise<void> {
+  return invoke('clear_git_lock', { path, lockPath })
+}
+
+// 브랜치에서 떨어져 나간 커밋 (최근 순). 복구는 create_branch_at 명령으로
+export async function findDanglingCommits(path: string): Promise<CommitInfo[]> {
+  return invoke<CommitInfo[]>('find_dangling_commits', { path })
+}
+
+// ============ 로컬 변경 숨기기 ============
+
+export interface HiddenFileInfo {
+  path: string
+  assume_unchanged: boolean
+  skip_worktree: boolean
+}
+
+export async function setAssumeUnchanged(path: string, filePath: string, enabled: boolean): Promise<void> {
+  return invoke('set_assume_unchanged', { path, filePath, enabled })
+}
+
+export async function setSkipWorktree(path: string, filePath: string, enabled: boolean): Promise<void> {
+  return invoke('set_skip_worktree', { path, filePath, enabled })
+}
+
+export async function listAssumeUnchanged(path: string): Promise<HiddenFileInfo[]> {
+  return invoke<HiddenFileInfo[]>('list_assume_unchanged', { path })
+}
+
+// ============ 훅 ============
+
+export interface HookInfo {
+  name: string
+  enabled: boolean
+  is_sample: boolean
+}
+
+export async function listHooks(path: string): Promise<HookInfo[]> {
+  return invoke<HookInfo[]>('list_hooks', { path })
+}
+
+// ============ 충돌 해결 ============
+
+export interface ConflictVersions {
+  base: string | null
+  ours: string | null
+  theirs: string | null
+  markers_include_base: boolean // diff3/zdiff3: 충돌 표시에 base 구간 포함
+}
+
+export async function getConflictVersions(path: string, filePath: string): Promise<ConflictVersions> {
+  return invoke<ConflictVersions>('get_conflict_versions', { path, filePath })
+}
+
+export async function writeConflictResolution(path: string, filePath: string, content: string): Promise<void> {
+  return invoke('write_conflict_resolution', { path, filePath, content })
+}
+
+// ============ 태그 ============
+
+export interface TagInfo {
+  name: string
+  commit_hash: string
+  message: string | null
+  time: number
+}
+
+export type TagSort = 'version' | 'date' | 'name'
+
+export async function getTags(path: string, sort: TagSort = 'version'): Promise<TagInfo[]> {
+  return invoke<TagInfo[]>('get_tags', { path, sort })
+}
+
+// 릴리스 노트용: fromTag 이후 toTag(생략 시 HEAD)까지의 커밋
+export async function commitsBetweenTags(
+  path: string,
+  fromTag: string,
+  toTag?: string,
+  excludeMerges: boolean = false
+): Promise<CommitInfo[]> {
+  return invoke<CommitInfo[]>('commits_between_tags', { path, fromTag, toTag, excludeMerges })
+}
+
+// ============ 설정 ============
+
+export interface ConfigEntry {
+  key: string
+  value: string
+  level: 'system' | 'xdg' | 'global' | 'local' | 'worktree' | 'app'
+}
+
+export async function getMergedConfig(path: string): Promise<ConfigEntry[]> {
+  return invoke<ConfigEntry[]>('get_merged_config', { path })
+}
+
+export interface LineEndingConfig {
+  autocrlf: 'true' | 'false' | 'input' | null
+  eol: string | null
+  gitattributes_sets_text: boolean // true면 .gitattributes가 autocrlf보다 우선
+}
+
+export async function getLineEndingConfig(path: string): Promise<LineEndingConfig> {
+  return invoke<LineEndingConfig>('get_line_ending_config', { path })
+}
+
+export async function setAutocrlf(path: string, value: 'true' | 'false' | 'input', global: boolean = false): Promise<void> {
+  return invoke('set_autocrlf', { path, value, global })
+}
+
+export type ConflictStyle = 'merge' | 'diff3' | 'zdiff3'
+
+// 설정 안 됨이면 'merge'
+export async function getConflictStyle(path: string): Promise<ConflictStyle> {
+  return invoke<ConflictStyle>('get_conflict_style', { path })
+}
+
+export async function setConflictStyle(path: string, style: ConflictStyle, global: boolean = false): Promise<void> {
+  return invoke('set_conflict_style', { path, style, global })
+}
+
+// ============ 읽기 전용 git 명령 실행 ============
+
+export interface GitCommandResult {
+  stdout: string
+  stderr: string
+  exit_code: number
+}
+
+// args[0]은 log, show, diff 등 읽기 전용 하위 명령이어야 함 (그 외에는 거부)
+export async function runGitReadonly(path: string, args: string[]): Promise<GitCommandResult> {
+  return invoke<GitCommandResult>('run_git_readonly', { path, args })
+}
+
+// ============ 스냅샷 내보내기 ============
+
+export async function exportArchive(
+  path: string,
+  gitRef: string,
+  format: 'zip' | 'tar' | 'tar.gz',
+  outputPath: string
+): Promise<void> {
+  return invoke('export_archive', { path, gitRef, format, outputPath })
+}
+
+// ============ sparse checkout ============
+
+export async function getSparseCheckout(path: string): Promise<string[]> {
+  return invoke<string[]>('get_sparse_checkout', { path })
+}
+
+// cone: 디렉토리 단위 패턴 (예: "packages/app")
+export async function setSparseCheckout(path: string, patterns: string[], cone: boolean = true): Promise<void> {
+  return invoke('set_sparse_checkout', { path, patterns, cone })
+}
+
+export async function disableSparseCheckout(path: string): Promise<void> {
+  return invoke('disable_sparse_checkout', { path })
+}
+
+// ============ 서브모듈 ============
+
+export interface SubmoduleStatus {
+  name: string
+  path: string
+  // behind: 업데이트 필요, ahead: 서브모듈에 기록되지 않은 커밋 있음
+  state: 'uninitialized' | 'clean' | 'modified' | 'ahead' | 'behind'
+  head_short: string | null
+  index_short: string | null
+}
+
+export async function getSubmoduleStatus(path: string): Promise<SubmoduleStatus[]> {
+  return invoke<SubmoduleStatus[]>('get_submodule_status', { path })
+}